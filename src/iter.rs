@@ -0,0 +1,123 @@
+//! Iterators that are statically guaranteed to yield at least one item.
+
+/// An iterator that is statically guaranteed to yield at least one item.
+///
+/// Obtained via [`NonEmpty::iter`](crate::NonEmpty::iter) or
+/// [`NonEmpty::into_nonempty_iter`](crate::NonEmpty::into_nonempty_iter). Because the guarantee is
+/// known up front, the terminal reductions [`NonEmptyIter::last`], [`NonEmptyIter::reduce`],
+/// [`NonEmptyIter::fold_first`], [`NonEmptyIter::max`] and [`NonEmptyIter::min`] return `Item`
+/// directly instead of `Option<Item>`, and truly never fail.
+///
+/// Unlike a plain [`Iterator`], `NonEmptyIter` does not implement `Iterator` itself and has no
+/// `next()`: stepping through items one at a time would let the guaranteed first item be drained
+/// away before a terminal reduction runs, breaking the guarantee above. Convert via
+/// [`IntoIterator`] to get a regular, steppable iterator once that's what you actually need; doing
+/// so forfeits the infallibility guarantee, same as any other `Iterator`.
+pub struct NonEmptyIter<I: Iterator> {
+  first: I::Item,
+  rest: I,
+}
+
+impl<I: Iterator> NonEmptyIter<I> {
+  pub(crate) fn new(first: I::Item, rest: I) -> Self {
+    NonEmptyIter { first, rest }
+  }
+
+  /// Consume the iterator, returning the last item.
+  pub fn last(self) -> I::Item {
+    self.rest.last().unwrap_or(self.first)
+  }
+
+  /// Reduce the iterator to a single item by repeatedly applying `f`, seeded with the first item.
+  pub fn reduce<F>(self, f: F) -> I::Item
+  where
+    F: FnMut(I::Item, I::Item) -> I::Item,
+  {
+    self.rest.fold(self.first, f)
+  }
+
+  /// Alias for [`NonEmptyIter::reduce`], matching the terminology used by non-empty collections
+  /// elsewhere in the ecosystem.
+  pub fn fold_first<F>(self, f: F) -> I::Item
+  where
+    F: FnMut(I::Item, I::Item) -> I::Item,
+  {
+    self.reduce(f)
+  }
+
+  /// Get the maximum item.
+  pub fn max(self) -> I::Item
+  where
+    I::Item: Ord,
+  {
+    self.reduce(|a, b| a.max(b))
+  }
+
+  /// Get the minimum item.
+  pub fn min(self) -> I::Item
+  where
+    I::Item: Ord,
+  {
+    self.reduce(|a, b| a.min(b))
+  }
+}
+
+impl<I: Iterator> IntoIterator for NonEmptyIter<I> {
+  type Item = I::Item;
+  type IntoIter = core::iter::Chain<core::iter::Once<I::Item>, I>;
+
+  /// Convert into a regular, steppable iterator.
+  ///
+  /// This is the escape hatch for callers who want `next()`-based iteration instead of a terminal
+  /// reduction. The guarantees on `NonEmptyIter` don't carry over to the iterator returned here.
+  fn into_iter(self) -> Self::IntoIter {
+    core::iter::once(self.first).chain(self.rest)
+  }
+}
+
+/// Extension trait adding a fallible, non-empty-aware `collect` to any [`Iterator`].
+pub trait IteratorExt: Iterator + Sized {
+  /// Collect the iterator into a [`NonEmpty`](crate::NonEmpty).
+  ///
+  /// This fails (returns `None`) only when the iterator yields no items at all, which `collect`
+  /// cannot express directly.
+  fn collect_nonempty(self) -> Option<crate::NonEmpty<Self::Item>> {
+    crate::NonEmpty::from_vec(self.collect())
+  }
+}
+
+impl<I: Iterator> IteratorExt for I {}
+
+#[cfg(test)]
+mod tests {
+  use crate::NonEmpty;
+
+  fn three() -> NonEmpty<i32> {
+    NonEmpty::from_vec(alloc::vec![1, 2, 3]).unwrap()
+  }
+
+  #[test]
+  fn reduce_sums_every_item() {
+    assert_eq!(three().into_nonempty_iter().reduce(|a, b| a + b), 6);
+  }
+
+  #[test]
+  fn max_and_min() {
+    assert_eq!(three().into_nonempty_iter().max(), 3);
+    assert_eq!(three().into_nonempty_iter().min(), 1);
+  }
+
+  #[test]
+  fn last_returns_the_final_item() {
+    assert_eq!(three().into_nonempty_iter().last(), 3);
+  }
+
+  #[test]
+  fn into_iter_gives_a_regular_steppable_iterator() {
+    let mut iter = three().into_nonempty_iter().into_iter();
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next(), Some(2));
+    assert_eq!(iter.next(), Some(3));
+    assert_eq!(iter.next(), None);
+  }
+}
@@ -16,30 +16,35 @@
 //!
 //! The implementation provided by this module has all data (including the first element) in the
 //! same memory region, providing the exact same cache and runtime performance as a regular `Vec`.
+//!
+//! # Features
+//!
+//! This crate is `no_std` by default. [`NonEmptySlice`], the borrowed view, works without any
+//! allocator. The owned [`NonEmpty`] vector, its iterator and the `non_empty!` macro require the
+//! `alloc` feature. The `std` feature additionally enables std-only trait impls (such as
+//! `std::error::Error`). The `serde` feature enables `Serialize`/`Deserialize` for [`NonEmpty`]
+//! and requires `alloc`.
+
+#![no_std]
 
-/// A non-empty vector.
-pub struct NonEmpty<T>(Vec<T>);
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
-impl<T> NonEmpty<T> {
-  /// Construct a non-empty vector from a `Vec<T>`.
-  ///
-  /// This function fails if the input vector is empty.
-  pub fn from_vec(vec: Vec<T>) -> Option<Self> {
-    if vec.is_empty() {
-      None
-    } else {
-      Some(NonEmpty(vec))
-    }
-  }
+#[cfg(feature = "alloc")]
+mod iter;
+#[cfg(feature = "alloc")]
+mod macros;
+#[cfg(feature = "alloc")]
+mod non_empty;
+#[cfg(all(feature = "alloc", feature = "serde"))]
+mod serde_impl;
+mod slice;
 
-  /// Construct a non-empty vector from a `&[T]`.
-  ///
-  /// This function fails if the input slice is empty.
-  pub fn from_slice(slice: &[T]) -> Option<Self> where T: Clone {
-    if slice.is_empty() {
-      None
-    } else {
-      Some(NonEmpty(slice.to_owned()))
-    }
-  }
-}
+#[cfg(feature = "alloc")]
+pub use iter::{IteratorExt, NonEmptyIter};
+#[cfg(feature = "alloc")]
+pub use non_empty::{EmptyError, NonEmpty};
+pub use slice::NonEmptySlice;
@@ -0,0 +1,16 @@
+//! The [`non_empty!`](crate::non_empty) construction macro.
+
+/// Construct a [`NonEmpty`](crate::NonEmpty) from a literal list of elements, analogous to `vec!`.
+///
+/// Because the macro requires at least one element to match, an empty invocation is rejected at
+/// compile time rather than at runtime:
+///
+/// ```compile_fail
+/// let _ = non_empty::non_empty![];
+/// ```
+#[macro_export]
+macro_rules! non_empty {
+  ($first:expr $(, $rest:expr)* $(,)?) => {
+    $crate::NonEmpty::from_vec($crate::alloc::vec![$first $(, $rest)*]).unwrap()
+  };
+}
@@ -0,0 +1,284 @@
+//! The owned, allocating non-empty vector, gated behind the `alloc` feature.
+
+use alloc::borrow::ToOwned;
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::ops::{Bound, Deref, DerefMut, RangeBounds};
+
+use crate::{NonEmptyIter, NonEmptySlice};
+
+/// A non-empty vector.
+pub struct NonEmpty<T>(pub(crate) Vec<T>);
+
+/// Error returned when attempting to build a non-empty collection from an empty one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EmptyError;
+
+impl core::fmt::Display for EmptyError {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    write!(f, "cannot build a non-empty collection from an empty one")
+  }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EmptyError {}
+
+impl<T> NonEmpty<T> {
+  /// Construct a non-empty vector holding a single element.
+  pub fn new(first: T) -> Self {
+    NonEmpty(alloc::vec![first])
+  }
+
+  /// Construct a non-empty vector holding a single element.
+  ///
+  /// This is an alias for [`NonEmpty::new`], provided for parity with the rest of the ecosystem.
+  pub fn of(first: T) -> Self {
+    Self::new(first)
+  }
+
+  /// Construct a non-empty vector from a `Vec<T>`.
+  ///
+  /// This function fails if the input vector is empty.
+  pub fn from_vec(vec: Vec<T>) -> Option<Self> {
+    if vec.is_empty() {
+      None
+    } else {
+      Some(NonEmpty(vec))
+    }
+  }
+
+  /// Construct a non-empty vector from a `&[T]`.
+  ///
+  /// This function fails if the input slice is empty.
+  pub fn from_slice(slice: &[T]) -> Option<Self> where T: Clone {
+    if slice.is_empty() {
+      None
+    } else {
+      Some(NonEmpty(slice.to_owned()))
+    }
+  }
+
+  /// Append an element to the back.
+  pub fn push(&mut self, value: T) {
+    self.0.push(value);
+  }
+
+  /// Remove and return the last element, unless it’s the only one left.
+  ///
+  /// This refuses to remove the final element, so that a `NonEmpty` can never become empty:
+  /// it returns `None` when `len() == 1` instead of popping.
+  pub fn pop(&mut self) -> Option<T> {
+    if self.0.len() == 1 {
+      None
+    } else {
+      self.0.pop()
+    }
+  }
+
+  /// Remove and return the element at `index`, unless it’s the only one left.
+  ///
+  /// This refuses to remove the final element, so that a `NonEmpty` can never become empty:
+  /// it returns `None` when `len() == 1` instead of removing.
+  pub fn remove(&mut self, index: usize) -> Option<T> {
+    if self.0.len() == 1 {
+      None
+    } else {
+      Some(self.0.remove(index))
+    }
+  }
+
+  /// Shorten the vector, keeping the first `len` elements.
+  ///
+  /// Clamped to never drop below one element: `truncate(0)` behaves like `truncate(1)`.
+  pub fn truncate(&mut self, len: usize) {
+    self.0.truncate(len.max(1));
+  }
+
+  /// Remove all elements for which `f` returns `false`.
+  ///
+  /// Clamped to preserve the invariant: if `f` would reject every element, the vector is left
+  /// untouched instead of becoming empty.
+  pub fn retain<F>(&mut self, mut f: F) where F: FnMut(&T) -> bool {
+    if self.0.iter().any(&mut f) {
+      self.0.retain(f);
+    }
+  }
+
+  /// Remove and return the elements in `range`, clamped to leave at least one element behind.
+  pub fn drain<R>(&mut self, range: R) -> alloc::vec::Drain<'_, T>
+  where
+    R: RangeBounds<usize>,
+  {
+    let len = self.0.len();
+    let start = match range.start_bound() {
+      Bound::Included(&start) => start,
+      Bound::Excluded(&start) => start + 1,
+      Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+      Bound::Included(&end) => end + 1,
+      Bound::Excluded(&end) => end,
+      Bound::Unbounded => len,
+    };
+    let end = end.min(start + len.saturating_sub(1));
+
+    self.0.drain(start..end)
+  }
+
+  /// Get an iterator over references to the elements.
+  ///
+  /// The returned [`NonEmptyIter`] knows statically that it will yield at least one item.
+  pub fn iter(&self) -> NonEmptyIter<core::slice::Iter<'_, T>> {
+    let mut iter = self.0.iter();
+    let first = iter.next().unwrap(); // never empty, by construction
+    NonEmptyIter::new(first, iter)
+  }
+
+  /// Consume the vector, returning an iterator over its elements.
+  ///
+  /// The returned [`NonEmptyIter`] knows statically that it will yield at least one item.
+  pub fn into_nonempty_iter(self) -> NonEmptyIter<alloc::vec::IntoIter<T>> {
+    let mut iter = self.0.into_iter();
+    let first = iter.next().unwrap(); // never empty, by construction
+    NonEmptyIter::new(first, iter)
+  }
+}
+
+impl<T> IntoIterator for NonEmpty<T> {
+  type Item = T;
+  type IntoIter = core::iter::Chain<core::iter::Once<T>, alloc::vec::IntoIter<T>>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    self.into_nonempty_iter().into_iter()
+  }
+}
+
+impl<T> Deref for NonEmpty<T> {
+  type Target = NonEmptySlice<T>;
+
+  fn deref(&self) -> &NonEmptySlice<T> {
+    // SAFETY: self.0 is never empty, by construction.
+    NonEmptySlice::from_slice(&self.0).unwrap()
+  }
+}
+
+impl<T> DerefMut for NonEmpty<T> {
+  fn deref_mut(&mut self) -> &mut NonEmptySlice<T> {
+    // SAFETY: self.0 is never empty, by construction.
+    NonEmptySlice::from_mut_slice(&mut self.0).unwrap()
+  }
+}
+
+impl<T> TryFrom<Vec<T>> for NonEmpty<T> {
+  type Error = EmptyError;
+
+  fn try_from(vec: Vec<T>) -> Result<Self, Self::Error> {
+    NonEmpty::from_vec(vec).ok_or(EmptyError)
+  }
+}
+
+impl<T: Clone> TryFrom<&[T]> for NonEmpty<T> {
+  type Error = EmptyError;
+
+  fn try_from(slice: &[T]) -> Result<Self, Self::Error> {
+    NonEmpty::from_slice(slice).ok_or(EmptyError)
+  }
+}
+
+impl<T> From<NonEmpty<T>> for Vec<T> {
+  fn from(non_empty: NonEmpty<T>) -> Self {
+    non_empty.0
+  }
+}
+
+impl<T> From<NonEmpty<T>> for Box<NonEmptySlice<T>> {
+  fn from(non_empty: NonEmpty<T>) -> Self {
+    // unwrap() is safe here as non_empty.0 is never empty, by construction.
+    NonEmptySlice::from_boxed_slice(non_empty.0.into_boxed_slice()).unwrap()
+  }
+}
+
+impl<T> From<NonEmpty<T>> for Arc<NonEmptySlice<T>> {
+  fn from(non_empty: NonEmpty<T>) -> Self {
+    Arc::from(Box::<NonEmptySlice<T>>::from(non_empty))
+  }
+}
+
+impl<T: PartialEq> PartialEq<Vec<T>> for NonEmpty<T> {
+  fn eq(&self, other: &Vec<T>) -> bool {
+    self.0 == *other
+  }
+}
+
+impl<T: PartialEq> PartialEq<[T]> for NonEmpty<T> {
+  fn eq(&self, other: &[T]) -> bool {
+    self.0 == *other
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn five() -> NonEmpty<i32> {
+    NonEmpty::from_vec(alloc::vec![0, 1, 2, 3, 4]).unwrap()
+  }
+
+  #[test]
+  fn pop_refuses_the_last_element() {
+    let mut one = NonEmpty::new(0);
+    assert_eq!(one.pop(), None);
+    assert_eq!(one.as_slice(), &[0]);
+
+    let mut two = NonEmpty::from_vec(alloc::vec![0, 1]).unwrap();
+    assert_eq!(two.pop(), Some(1));
+    assert_eq!(two.pop(), None);
+  }
+
+  #[test]
+  fn truncate_clamps_to_one() {
+    let mut v = five();
+    v.truncate(0);
+    assert_eq!(v.as_slice(), &[0]);
+  }
+
+  #[test]
+  fn retain_is_a_no_op_when_nothing_would_survive() {
+    let mut v = five();
+    v.retain(|_| false);
+    assert_eq!(v.as_slice(), &[0, 1, 2, 3, 4]);
+  }
+
+  #[test]
+  fn retain_keeps_only_matching_elements() {
+    let mut v = five();
+    v.retain(|&x| x % 2 == 0);
+    assert_eq!(v.as_slice(), &[0, 2, 4]);
+  }
+
+  #[test]
+  fn drain_partial_range_keeps_unselected_tail() {
+    let mut v = five();
+    let drained: Vec<_> = v.drain(3..5).collect();
+    assert_eq!(drained, alloc::vec![3, 4]);
+    assert_eq!(v.as_slice(), &[0, 1, 2]);
+  }
+
+  #[test]
+  fn drain_can_remove_down_to_the_last_element() {
+    let mut v = NonEmpty::from_vec(alloc::vec![0, 1, 2]).unwrap();
+    let drained: Vec<_> = v.drain(2..3).collect();
+    assert_eq!(drained, alloc::vec![2]);
+    assert_eq!(v.as_slice(), &[0, 1]);
+  }
+
+  #[test]
+  fn drain_full_range_clamps_to_leave_one_element() {
+    let mut v = five();
+    let drained: Vec<_> = v.drain(0..5).collect();
+    assert_eq!(drained, alloc::vec![0, 1, 2, 3]);
+    assert_eq!(v.as_slice(), &[4]);
+  }
+}
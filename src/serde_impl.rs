@@ -0,0 +1,55 @@
+//! Optional [`serde`](https://docs.rs/serde) support, enabled via the `serde` feature.
+//!
+//! `NonEmpty<T>` serializes exactly like `Vec<T>`. Deserialization goes through `Vec<T>` first and
+//! then fails if the decoded sequence turns out to be empty, so the non-empty invariant holds
+//! across a round-trip.
+
+use alloc::vec::Vec;
+
+use serde::de::{Deserialize, Deserializer, Error as _};
+use serde::ser::{Serialize, Serializer};
+
+use crate::NonEmpty;
+
+impl<T: Serialize> Serialize for NonEmpty<T> {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    self.0.serialize(serializer)
+  }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for NonEmpty<T> {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    let vec = Vec::<T>::deserialize(deserializer)?;
+    NonEmpty::from_vec(vec).ok_or_else(|| D::Error::custom("expected a non-empty sequence"))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use alloc::string::ToString;
+
+  use super::*;
+
+  #[test]
+  fn round_trips_through_serde_json() {
+    let v = NonEmpty::from_vec(alloc::vec![1, 2, 3]).unwrap();
+    let json = serde_json::to_string(&v).unwrap();
+    let back: NonEmpty<i32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.as_slice(), &[1, 2, 3]);
+  }
+
+  #[test]
+  fn deserializing_an_empty_sequence_fails_with_the_custom_error() {
+    let err = match serde_json::from_str::<NonEmpty<i32>>("[]") {
+      Ok(_) => panic!("expected deserialization of an empty sequence to fail"),
+      Err(err) => err,
+    };
+    assert!(err.to_string().contains("expected a non-empty sequence"));
+  }
+}
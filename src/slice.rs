@@ -0,0 +1,144 @@
+//! A borrowed, contiguous view over a non-empty slice.
+
+use core::num::NonZeroUsize;
+
+/// A non-empty slice.
+///
+/// Unlike [`NonEmpty`](crate::NonEmpty), this type borrows its data instead of owning it, which
+/// means it can be built directly from an existing `&[T]` / `&mut [T]` without any allocation or
+/// copy. Its representation is `#[repr(transparent)]` over `[T]`, so a reference to it is obtained
+/// from a reference to `[T]` via a checked pointer cast rather than a conversion.
+#[repr(transparent)]
+pub struct NonEmptySlice<T>([T]);
+
+impl<T> NonEmptySlice<T> {
+  /// Construct a non-empty slice from a `&[T]`.
+  ///
+  /// This function fails if the input slice is empty.
+  pub fn from_slice(slice: &[T]) -> Option<&Self> {
+    if slice.is_empty() {
+      None
+    } else {
+      // SAFETY: NonEmptySlice<T> is #[repr(transparent)] over [T], so the layouts match; we just
+      // checked the slice is non-empty.
+      Some(unsafe { &*(slice as *const [T] as *const NonEmptySlice<T>) })
+    }
+  }
+
+  /// Construct a non-empty slice from a `&mut [T]`.
+  ///
+  /// This function fails if the input slice is empty.
+  pub fn from_mut_slice(slice: &mut [T]) -> Option<&mut Self> {
+    if slice.is_empty() {
+      None
+    } else {
+      // SAFETY: see from_slice.
+      Some(unsafe { &mut *(slice as *mut [T] as *mut NonEmptySlice<T>) })
+    }
+  }
+
+  /// Get a reference to the underlying slice.
+  pub fn as_slice(&self) -> &[T] {
+    &self.0
+  }
+
+  /// Get a mutable reference to the underlying slice.
+  pub fn as_mut_slice(&mut self) -> &mut [T] {
+    &mut self.0
+  }
+
+  /// Construct a non-empty boxed slice from a `Box<[T]>`.
+  ///
+  /// This function fails if the input slice is empty.
+  #[cfg(feature = "alloc")]
+  pub fn from_boxed_slice(boxed: alloc::boxed::Box<[T]>) -> Option<alloc::boxed::Box<Self>> {
+    use alloc::boxed::Box;
+
+    if boxed.is_empty() {
+      None
+    } else {
+      let raw = Box::into_raw(boxed);
+      // SAFETY: see from_slice; NonEmptySlice<T> is #[repr(transparent)] over [T].
+      Some(unsafe { Box::from_raw(raw as *mut NonEmptySlice<T>) })
+    }
+  }
+
+  /// Get a reference to the first element.
+  ///
+  /// Unlike `[T]::first`, this never fails, since a `NonEmptySlice` is guaranteed to hold at
+  /// least one element.
+  pub fn first(&self) -> &T {
+    &self.0[0]
+  }
+
+  /// Get a mutable reference to the first element.
+  pub fn first_mut(&mut self) -> &mut T {
+    &mut self.0[0]
+  }
+
+  /// Get a reference to the last element.
+  ///
+  /// Unlike `[T]::last`, this never fails, since a `NonEmptySlice` is guaranteed to hold at
+  /// least one element.
+  pub fn last(&self) -> &T {
+    &self.0[self.0.len() - 1]
+  }
+
+  /// Get a mutable reference to the last element.
+  pub fn last_mut(&mut self) -> &mut T {
+    let last = self.0.len() - 1;
+    &mut self.0[last]
+  }
+
+  /// Get the number of elements.
+  ///
+  /// This is guaranteed to be non-zero, hence the [`NonZeroUsize`] return type.
+  pub fn len(&self) -> NonZeroUsize {
+    // unwrap() is safe here as self.0 is never empty, by construction.
+    NonZeroUsize::new(self.0.len()).unwrap()
+  }
+
+  /// Always `false`: a `NonEmptySlice` can never be empty.
+  pub fn is_empty(&self) -> bool {
+    false
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn from_slice_rejects_empty_and_accepts_non_empty() {
+    let empty: &[i32] = &[];
+    assert!(NonEmptySlice::from_slice(empty).is_none());
+
+    let slice = [1, 2, 3];
+    let non_empty = NonEmptySlice::from_slice(&slice).unwrap();
+    assert_eq!(non_empty.as_slice(), &slice);
+    assert_eq!(non_empty.first(), &1);
+    assert_eq!(non_empty.last(), &3);
+  }
+
+  #[test]
+  fn from_mut_slice_mutates_through_the_original_backing_memory() {
+    let mut slice = [1, 2, 3];
+    {
+      let non_empty = NonEmptySlice::from_mut_slice(&mut slice).unwrap();
+      *non_empty.first_mut() = 10;
+      *non_empty.last_mut() = 30;
+    }
+    assert_eq!(slice, [10, 2, 30]);
+  }
+
+  #[cfg(feature = "alloc")]
+  #[test]
+  fn from_boxed_slice_rejects_empty_and_accepts_non_empty() {
+    let empty: alloc::boxed::Box<[i32]> = alloc::vec![].into_boxed_slice();
+    assert!(NonEmptySlice::from_boxed_slice(empty).is_none());
+
+    let boxed: alloc::boxed::Box<[i32]> = alloc::vec![1, 2, 3].into_boxed_slice();
+    let non_empty = NonEmptySlice::from_boxed_slice(boxed).unwrap();
+    assert_eq!(non_empty.as_slice(), &[1, 2, 3]);
+  }
+}